@@ -0,0 +1,194 @@
+//! Loading, writing, and copying car-related binary assets (photos,
+//! registration documents) from either a local filesystem path or an
+//! `http(s)` URL, so callers can treat both uniformly.
+
+use std::path::Path;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::FleetError;
+
+/// Maximum time allowed to fetch a remote asset before giving up.
+const REMOTE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Maximum accepted asset size, to bound memory use for a misconfigured
+/// or hostile `image_url`.
+const MAX_ASSET_BYTES: usize = 25 * 1024 * 1024;
+
+/// Bytes plus the detected filename and MIME type of a loaded asset.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Asset {
+    pub bytes: Vec<u8>,
+    pub filename: String,
+    pub mime_type: String,
+}
+
+/// Loads an asset from `source`, which may be a local filesystem path or
+/// an `http(s)` URL.
+pub async fn load_asset(source: &str) -> Result<Asset, FleetError> {
+    if source.starts_with("http://") || source.starts_with("https://") {
+        load_remote(source).await
+    } else {
+        load_local(source).await
+    }
+}
+
+/// Writes `asset` to `path` on the local filesystem.
+pub async fn write_asset(asset: &Asset, path: impl AsRef<Path>) -> Result<(), FleetError> {
+    tokio::fs::write(path.as_ref(), &asset.bytes).await.map_err(|err| {
+        FleetError::AssetUnavailable(format!("failed to write {}: {err}", path.as_ref().display()))
+    })
+}
+
+/// Loads an asset from `source` and writes it to `dest`, so copying a
+/// photo from a remote fleet service to local disk is one call.
+pub async fn copy_asset(source: &str, dest: impl AsRef<Path>) -> Result<Asset, FleetError> {
+    let asset = load_asset(source).await?;
+    write_asset(&asset, dest).await?;
+    Ok(asset)
+}
+
+async fn load_local(path: &str) -> Result<Asset, FleetError> {
+    let path = Path::new(path);
+    let bytes = tokio::fs::read(path)
+        .await
+        .map_err(|err| FleetError::AssetUnavailable(format!("failed to read {}: {err}", path.display())))?;
+    let filename = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "asset".to_string());
+    let mime_type = mime_guess::from_path(path).first_or_octet_stream().to_string();
+    Ok(Asset {
+        bytes,
+        filename,
+        mime_type,
+    })
+}
+
+async fn load_remote(url: &str) -> Result<Asset, FleetError> {
+    let client = reqwest::Client::builder()
+        .timeout(REMOTE_TIMEOUT)
+        .build()
+        .expect("reqwest client should always build with the default TLS config");
+
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .and_then(|resp| resp.error_for_status())
+        .map_err(|err| FleetError::RemoteUnavailable(err.to_string()))?;
+
+    if let Some(len) = response.content_length() {
+        if len as usize > MAX_ASSET_BYTES {
+            return Err(FleetError::RemoteUnavailable(format!(
+                "asset at {url} is {len} bytes, exceeding the {MAX_ASSET_BYTES}-byte limit"
+            )));
+        }
+    }
+
+    let mime_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or("application/octet-stream")
+        .to_string();
+
+    let filename = filename_from_url(url).unwrap_or_else(|| {
+        let extension = mime_guess::get_mime_extensions_str(&mime_type)
+            .and_then(|extensions| extensions.first())
+            .copied()
+            .unwrap_or("bin");
+        format!("asset.{extension}")
+    });
+
+    let bytes = read_body_capped(response, url, MAX_ASSET_BYTES).await?;
+
+    Ok(Asset {
+        bytes,
+        filename,
+        mime_type,
+    })
+}
+
+/// Reads `response`'s body in chunks, bailing out as soon as the total
+/// exceeds `limit` rather than buffering an unbounded body first (a
+/// declared `Content-Length` can't be trusted on its own). `limit` is
+/// [`MAX_ASSET_BYTES`] in production; taken as a parameter so tests can
+/// exercise the cap without transferring tens of megabytes.
+async fn read_body_capped(response: reqwest::Response, url: &str, limit: usize) -> Result<Vec<u8>, FleetError> {
+    use futures_util::StreamExt;
+
+    let mut stream = response.bytes_stream();
+    let mut bytes = Vec::new();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|err| FleetError::RemoteUnavailable(err.to_string()))?;
+        if bytes.len() + chunk.len() > limit {
+            return Err(FleetError::RemoteUnavailable(format!(
+                "asset at {url} exceeds the {limit}-byte limit"
+            )));
+        }
+        bytes.extend_from_slice(&chunk);
+    }
+    Ok(bytes)
+}
+
+/// Pulls a filename out of a URL's path component, e.g.
+/// `https://host/photos/car-1.jpg?x=1` -> `car-1.jpg`. Returns `None` when
+/// the URL has no path segment that looks like a filename, so the caller
+/// can fall back to inferring one from the response's content type.
+fn filename_from_url(url: &str) -> Option<String> {
+    let path = url.split('?').next().unwrap_or(url);
+    // Drop the scheme and host so a bare `https://host.tld` is not taken
+    // for a filename.
+    let path = path.split_once("://").map_or(path, |(_, rest)| rest);
+    let (_, path) = path.split_once('/')?;
+    let name = path.rsplit('/').next()?;
+    if name.is_empty() || !name.contains('.') {
+        None
+    } else {
+        Some(name.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Starts a server on an ephemeral local port that serves a single
+    /// request with a body of `body_len` zero bytes, then returns its URL.
+    async fn serve_body(body_len: usize) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = [0u8; 1024];
+            let _ = socket.read(&mut buf).await;
+            let header = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/octet-stream\r\nContent-Length: {body_len}\r\nConnection: close\r\n\r\n"
+            );
+            socket.write_all(header.as_bytes()).await.unwrap();
+            socket.write_all(&vec![0u8; body_len]).await.unwrap();
+            socket.shutdown().await.unwrap();
+        });
+        format!("http://{addr}/asset.bin")
+    }
+
+    #[tokio::test]
+    async fn read_body_capped_accepts_a_body_within_the_limit() {
+        let url = serve_body(10).await;
+        let response = reqwest::get(&url).await.unwrap();
+        let bytes = read_body_capped(response, &url, 16).await.unwrap();
+        assert_eq!(bytes.len(), 10);
+    }
+
+    #[tokio::test]
+    async fn read_body_capped_rejects_a_body_exceeding_the_limit() {
+        let url = serve_body(32).await;
+        let response = reqwest::get(&url).await.unwrap();
+        let err = read_body_capped(response, &url, 16).await.unwrap_err();
+        assert!(matches!(err, FleetError::RemoteUnavailable(_)));
+    }
+}
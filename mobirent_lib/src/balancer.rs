@@ -0,0 +1,272 @@
+//! Backend-selection strategies for [`FleetProxy`](crate::proxy::FleetProxy)
+//! when it fronts more than one remote backend.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicI64, AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use rand::seq::SliceRandom;
+
+/// How long a backend is skipped after being marked unhealthy.
+const UNHEALTHY_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// Number of consecutive failures before a backend is marked unhealthy.
+const FAILURE_THRESHOLD: usize = 3;
+
+/// Per-backend bookkeeping used by the load balancer to pick a target and
+/// by the proxy to record the outcome of each call.
+#[derive(Debug)]
+pub(crate) struct BackendHealth {
+    weight: u32,
+    current_weight: AtomicI64,
+    in_flight: AtomicUsize,
+    consecutive_failures: AtomicUsize,
+    unhealthy_until: Mutex<Option<Instant>>,
+}
+
+impl BackendHealth {
+    pub(crate) fn new(weight: u32) -> Self {
+        Self {
+            weight: weight.max(1),
+            current_weight: AtomicI64::new(0),
+            in_flight: AtomicUsize::new(0),
+            consecutive_failures: AtomicUsize::new(0),
+            unhealthy_until: Mutex::new(None),
+        }
+    }
+
+    pub(crate) fn is_healthy(&self) -> bool {
+        match *self.unhealthy_until.lock().unwrap() {
+            Some(until) => Instant::now() >= until,
+            None => true,
+        }
+    }
+
+    pub(crate) fn begin_call(&self) {
+        self.in_flight.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn end_call(&self) {
+        self.in_flight.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_success(&self) {
+        self.consecutive_failures.store(0, Ordering::Relaxed);
+        *self.unhealthy_until.lock().unwrap() = None;
+    }
+
+    pub(crate) fn record_failure(&self) {
+        let failures = self.consecutive_failures.fetch_add(1, Ordering::Relaxed) + 1;
+        if failures >= FAILURE_THRESHOLD {
+            *self.unhealthy_until.lock().unwrap() = Some(Instant::now() + UNHEALTHY_COOLDOWN);
+        }
+    }
+
+    fn in_flight_count(&self) -> usize {
+        self.in_flight.load(Ordering::Relaxed)
+    }
+}
+
+/// Strategy used to pick which backend handles the next call.
+#[derive(Debug)]
+pub enum LoadBalancer {
+    /// Cycles through healthy backends in order.
+    RoundRobin(AtomicUsize),
+    /// Picks a healthy backend uniformly at random.
+    Random,
+    /// Picks the healthy backend with the fewest in-flight requests.
+    LeastConnections,
+    /// Picks proportionally to each backend's configured weight using a
+    /// smooth weighted round-robin counter (as used by nginx).
+    WeightedRoundRobin,
+    /// Hashes a caller-supplied key to consistently route to the same
+    /// backend.
+    IpHash,
+}
+
+impl LoadBalancer {
+    pub fn round_robin() -> Self {
+        LoadBalancer::RoundRobin(AtomicUsize::new(0))
+    }
+
+    pub fn random() -> Self {
+        LoadBalancer::Random
+    }
+
+    pub fn least_connections() -> Self {
+        LoadBalancer::LeastConnections
+    }
+
+    pub fn weighted_round_robin() -> Self {
+        LoadBalancer::WeightedRoundRobin
+    }
+
+    pub fn ip_hash() -> Self {
+        LoadBalancer::IpHash
+    }
+
+    /// Picks the index of the next backend to use out of `health`, skipping
+    /// any currently-unhealthy backends. Returns `None` if every backend is
+    /// unhealthy.
+    pub(crate) fn select(&self, health: &[&BackendHealth], key: Option<&str>) -> Option<usize> {
+        let healthy: Vec<usize> = health
+            .iter()
+            .enumerate()
+            .filter(|(_, h)| h.is_healthy())
+            .map(|(i, _)| i)
+            .collect();
+
+        if healthy.is_empty() {
+            return None;
+        }
+
+        match self {
+            LoadBalancer::RoundRobin(counter) => {
+                let n = counter.fetch_add(1, Ordering::Relaxed);
+                Some(healthy[n % healthy.len()])
+            }
+            LoadBalancer::Random => healthy.choose(&mut rand::thread_rng()).copied(),
+            LoadBalancer::LeastConnections => healthy
+                .into_iter()
+                .min_by_key(|&i| health[i].in_flight_count()),
+            LoadBalancer::WeightedRoundRobin => {
+                let total_weight: i64 = healthy.iter().map(|&i| health[i].weight as i64).sum();
+                let mut best: Option<(usize, i64)> = None;
+                for &i in &healthy {
+                    let w = health[i].weight as i64;
+                    let current = health[i].current_weight.fetch_add(w, Ordering::Relaxed) + w;
+                    if best.is_none_or(|(_, best_current)| current > best_current) {
+                        best = Some((i, current));
+                    }
+                }
+                if let Some((chosen, _)) = best {
+                    health[chosen]
+                        .current_weight
+                        .fetch_sub(total_weight, Ordering::Relaxed);
+                }
+                best.map(|(i, _)| i)
+            }
+            LoadBalancer::IpHash => {
+                // Hash over the full backend count (not just the healthy
+                // ones) so a key's preferred backend only changes when
+                // that backend itself goes unhealthy, not whenever some
+                // unrelated backend's health flips.
+                let mut hasher = DefaultHasher::new();
+                key.unwrap_or("").hash(&mut hasher);
+                let start = (hasher.finish() as usize) % health.len();
+                (0..health.len())
+                    .map(|offset| (start + offset) % health.len())
+                    .find(|&idx| health[idx].is_healthy())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn healthy_pool(weights: &[u32]) -> Vec<BackendHealth> {
+        weights.iter().map(|&w| BackendHealth::new(w)).collect()
+    }
+
+    fn refs(pool: &[BackendHealth]) -> Vec<&BackendHealth> {
+        pool.iter().collect()
+    }
+
+    #[test]
+    fn round_robin_cycles_in_order() {
+        let pool = healthy_pool(&[1, 1, 1]);
+        let balancer = LoadBalancer::round_robin();
+        let picks: Vec<usize> = (0..6)
+            .map(|_| balancer.select(&refs(&pool), None).unwrap())
+            .collect();
+        assert_eq!(picks, vec![0, 1, 2, 0, 1, 2]);
+    }
+
+    #[test]
+    fn round_robin_skips_unhealthy_backends() {
+        let pool = healthy_pool(&[1, 1, 1]);
+        pool[1].record_failure();
+        pool[1].record_failure();
+        pool[1].record_failure();
+        assert!(!pool[1].is_healthy());
+
+        let balancer = LoadBalancer::round_robin();
+        let picks: Vec<usize> = (0..4)
+            .map(|_| balancer.select(&refs(&pool), None).unwrap())
+            .collect();
+        assert_eq!(picks, vec![0, 2, 0, 2]);
+    }
+
+    #[test]
+    fn least_connections_picks_idlest_backend() {
+        let pool = healthy_pool(&[1, 1, 1]);
+        pool[0].begin_call();
+        pool[0].begin_call();
+        pool[1].begin_call();
+
+        let balancer = LoadBalancer::least_connections();
+        assert_eq!(balancer.select(&refs(&pool), None), Some(2));
+    }
+
+    #[test]
+    fn weighted_round_robin_distributes_proportionally_to_weight() {
+        let pool = healthy_pool(&[3, 1]);
+        let balancer = LoadBalancer::weighted_round_robin();
+        let mut counts = [0usize; 2];
+        for _ in 0..8 {
+            let idx = balancer.select(&refs(&pool), None).unwrap();
+            counts[idx] += 1;
+        }
+        // Weight ratio 3:1 over 8 picks should land on 6:2.
+        assert_eq!(counts, [6, 2]);
+    }
+
+    #[test]
+    fn ip_hash_is_sticky_for_the_same_key() {
+        let pool = healthy_pool(&[1, 1, 1, 1]);
+        let balancer = LoadBalancer::ip_hash();
+        let first = balancer.select(&refs(&pool), Some("caller-42")).unwrap();
+        for _ in 0..10 {
+            assert_eq!(
+                balancer.select(&refs(&pool), Some("caller-42")),
+                Some(first)
+            );
+        }
+    }
+
+    #[test]
+    fn ip_hash_only_remaps_when_its_own_backend_is_unhealthy() {
+        let pool = healthy_pool(&[1, 1, 1, 1]);
+        let balancer = LoadBalancer::ip_hash();
+        let original = balancer.select(&refs(&pool), Some("caller-42")).unwrap();
+
+        // Mark an unrelated backend unhealthy; the key's preferred
+        // backend should be unaffected unless it was the one marked down.
+        let other = (original + 1) % pool.len();
+        pool[other].record_failure();
+        pool[other].record_failure();
+        pool[other].record_failure();
+
+        let after = balancer.select(&refs(&pool), Some("caller-42")).unwrap();
+        if original == other {
+            assert_ne!(after, original);
+        } else {
+            assert_eq!(after, original);
+        }
+    }
+
+    #[test]
+    fn select_returns_none_when_all_backends_unhealthy() {
+        let pool = healthy_pool(&[1]);
+        pool[0].record_failure();
+        pool[0].record_failure();
+        pool[0].record_failure();
+
+        let balancer = LoadBalancer::round_robin();
+        assert_eq!(balancer.select(&refs(&pool), None), None);
+    }
+}
@@ -0,0 +1,264 @@
+//! In-memory or file-backed implementation of [`CacheBackend`].
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+
+use crate::traits::CacheBackend;
+
+#[derive(Debug, Clone)]
+struct CacheEntry {
+    bytes: Vec<u8>,
+    inserted_at: Instant,
+}
+
+/// On-disk representation of a [`CacheEntry`]. `Instant` has no fixed
+/// epoch and can't be serialized meaningfully across process restarts, so
+/// persisted entries carry a `SystemTime` instead; it's converted back to
+/// an `Instant` on load.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PersistedEntry {
+    bytes: Vec<u8>,
+    inserted_at: SystemTime,
+}
+
+/// Caches arbitrary byte values (fleet/car data serialized by the caller)
+/// to avoid redundant remote calls.
+///
+/// By default entries are kept in memory only and never expire. Use
+/// [`CacheManager::with_ttl`] to have entries considered stale after a
+/// configured duration, and [`CacheManager::file_backed`] to persist
+/// entries to disk between runs. For a cache shared across multiple
+/// MobiRent instances, see `RedisCacheBackend`.
+#[derive(Debug, Default)]
+pub struct CacheManager {
+    entries: RwLock<HashMap<String, CacheEntry>>,
+    ttl: Option<Duration>,
+    backing_file: Option<PathBuf>,
+}
+
+impl CacheManager {
+    /// Creates an empty in-memory cache whose entries never expire.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty in-memory cache whose entries are considered stale
+    /// after `ttl` has elapsed since insertion.
+    pub fn with_ttl(ttl: Duration) -> Self {
+        Self {
+            entries: RwLock::new(HashMap::new()),
+            ttl: Some(ttl),
+            backing_file: None,
+        }
+    }
+
+    /// Creates a cache that persists entries as MessagePack under `dir`,
+    /// loading any cache left over from a previous run. A decode error in
+    /// the existing file (e.g. from a format change) is treated the same
+    /// as a missing cache: the cache starts empty and remote fetches
+    /// repopulate it.
+    pub async fn file_backed(dir: impl AsRef<Path>) -> Self {
+        let dir = dir.as_ref();
+        if let Err(err) = tokio::fs::create_dir_all(dir).await {
+            log::warn!("could not create cache directory {}: {err}", dir.display());
+        }
+        let backing_file = dir.join("fleet_cache.mpack");
+
+        let entries = match tokio::fs::read(&backing_file).await {
+            Ok(bytes) => match rmp_serde::from_slice::<HashMap<String, PersistedEntry>>(&bytes) {
+                Ok(persisted) => persisted
+                    .into_iter()
+                    .map(|(key, entry)| (key, from_persisted(entry)))
+                    .collect(),
+                Err(err) => {
+                    log::warn!("ignoring unreadable cache file {}: {err}", backing_file.display());
+                    HashMap::new()
+                }
+            },
+            Err(_) => HashMap::new(),
+        };
+
+        Self {
+            entries: RwLock::new(entries),
+            ttl: None,
+            backing_file: Some(backing_file),
+        }
+    }
+
+    /// Resolves a sensible OS-specific cache directory for Mobirent, e.g.
+    /// `~/.cache/mobirent` on Linux. Falls back to the system temp
+    /// directory if no cache directory can be determined.
+    pub fn base_cache_dir() -> PathBuf {
+        dirs::cache_dir()
+            .unwrap_or_else(std::env::temp_dir)
+            .join("mobirent")
+    }
+
+    /// Removes all entries from the cache.
+    pub async fn clear(&self) {
+        self.entries.write().await.clear();
+        self.persist().await;
+    }
+
+    /// Returns true if the entry at `key` exists and is older than the
+    /// configured TTL (see [`CacheManager::with_ttl`]). Returns false for
+    /// missing entries or when no TTL is configured. Exposed so
+    /// telemetry-driven refresh logic can check freshness without forcing
+    /// a fetch through [`CacheBackend::get`].
+    pub async fn is_stale(&self, key: &str) -> bool {
+        let Some(ttl) = self.ttl else {
+            return false;
+        };
+        match self.entries.read().await.get(key) {
+            Some(entry) => entry.inserted_at.elapsed() > ttl,
+            None => false,
+        }
+    }
+
+    /// Writes the full cache to disk, if file-backed, as compact
+    /// MessagePack. Writes go to a temp file in the same directory and are
+    /// renamed into place, so a crash mid-write cannot corrupt the cache.
+    async fn persist(&self) {
+        let Some(backing_file) = &self.backing_file else {
+            return;
+        };
+
+        let persisted: HashMap<String, PersistedEntry> = self
+            .entries
+            .read()
+            .await
+            .iter()
+            .map(|(key, entry)| (key.clone(), to_persisted(entry)))
+            .collect();
+
+        let bytes = match rmp_serde::to_vec(&persisted) {
+            Ok(bytes) => bytes,
+            Err(err) => {
+                log::warn!("failed to encode cache for persistence: {err}");
+                return;
+            }
+        };
+
+        let tmp_path = backing_file.with_extension("mpack.tmp");
+        if let Err(err) = tokio::fs::write(&tmp_path, &bytes).await {
+            log::warn!("failed to write cache temp file {}: {err}", tmp_path.display());
+            return;
+        }
+        if let Err(err) = tokio::fs::rename(&tmp_path, backing_file).await {
+            log::warn!("failed to finalize cache file {}: {err}", backing_file.display());
+        }
+    }
+}
+
+#[async_trait]
+impl CacheBackend for CacheManager {
+    async fn get(&self, key: &str) -> Option<Vec<u8>> {
+        if self.is_stale(key).await {
+            // Evict immediately rather than leaving a stale entry in the
+            // map until the next `set` happens to overwrite it.
+            self.entries.write().await.remove(key);
+            self.persist().await;
+            return None;
+        }
+        self.entries.read().await.get(key).map(|e| e.bytes.clone())
+    }
+
+    async fn set(&self, key: &str, value: Vec<u8>) {
+        self.entries.write().await.insert(
+            key.to_string(),
+            CacheEntry {
+                bytes: value,
+                inserted_at: Instant::now(),
+            },
+        );
+        self.persist().await;
+    }
+
+    async fn invalidate(&self, key: &str) {
+        self.entries.write().await.remove(key);
+        self.persist().await;
+    }
+}
+
+fn to_persisted(entry: &CacheEntry) -> PersistedEntry {
+    PersistedEntry {
+        bytes: entry.bytes.clone(),
+        inserted_at: SystemTime::now() - entry.inserted_at.elapsed(),
+    }
+}
+
+fn from_persisted(entry: PersistedEntry) -> CacheEntry {
+    let age = SystemTime::now()
+        .duration_since(entry.inserted_at)
+        .unwrap_or_default();
+    // `age` can exceed how long this process (and its monotonic clock)
+    // has been running — e.g. a cache file persisted before the last
+    // reboot — in which case subtracting it from `Instant::now()` would
+    // underflow and panic. Fall back to treating the entry as inserted
+    // right now, so its age restarts from zero on load.
+    let inserted_at = Instant::now().checked_sub(age).unwrap_or_else(Instant::now);
+    CacheEntry {
+        bytes: entry.bytes,
+        inserted_at,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn entries_are_stale_after_ttl_elapses() {
+        let cache = CacheManager::with_ttl(Duration::from_millis(10));
+        cache.set("k", b"v".to_vec()).await;
+
+        assert!(!cache.is_stale("k").await);
+        assert_eq!(cache.get("k").await, Some(b"v".to_vec()));
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+
+        assert!(cache.is_stale("k").await);
+        assert_eq!(cache.get("k").await, None);
+    }
+
+    #[tokio::test]
+    async fn stale_get_evicts_the_entry() {
+        let cache = CacheManager::with_ttl(Duration::from_millis(10));
+        cache.set("k", b"v".to_vec()).await;
+        tokio::time::sleep(Duration::from_millis(30)).await;
+
+        assert_eq!(cache.get("k").await, None);
+        assert!(!cache.entries.read().await.contains_key("k"));
+    }
+
+    #[tokio::test]
+    async fn without_ttl_entries_never_go_stale() {
+        let cache = CacheManager::new();
+        cache.set("k", b"v".to_vec()).await;
+        assert!(!cache.is_stale("k").await);
+        assert_eq!(cache.get("k").await, Some(b"v".to_vec()));
+    }
+
+    #[tokio::test]
+    async fn missing_keys_are_not_stale() {
+        let cache = CacheManager::with_ttl(Duration::from_millis(10));
+        assert!(!cache.is_stale("missing").await);
+    }
+
+    #[test]
+    fn from_persisted_does_not_panic_on_pre_boot_timestamps() {
+        let entry = PersistedEntry {
+            bytes: b"v".to_vec(),
+            inserted_at: SystemTime::UNIX_EPOCH,
+        };
+        // Must not panic even though `UNIX_EPOCH` is almost certainly
+        // older than this machine's monotonic clock uptime.
+        let restored = from_persisted(entry);
+        assert_eq!(restored.bytes, b"v");
+    }
+}
@@ -0,0 +1,31 @@
+//! Error types shared across the Mobirent library.
+
+use thiserror::Error;
+
+/// Errors that can occur while accessing fleet or car data, whether served
+/// from cache or fetched from a remote backend.
+#[derive(Debug, Error)]
+pub enum FleetError {
+    /// The remote fleet service could not be reached or returned an error.
+    #[error("remote fleet service unavailable: {0}")]
+    RemoteUnavailable(String),
+
+    /// No car with the given id exists in the fleet.
+    #[error("car not found: {0}")]
+    CarNotFound(String),
+
+    /// The cache layer failed to read or write an entry.
+    #[error("cache error: {0}")]
+    Cache(String),
+
+    /// The caller exceeded the configured rate limit and was rejected
+    /// rather than queued.
+    #[error("rate limit exceeded")]
+    RateLimited,
+
+    /// A car asset (photo, document) could not be obtained: either no
+    /// source is configured, or loading/writing it via the local
+    /// filesystem failed.
+    #[error("no asset available: {0}")]
+    AssetUnavailable(String),
+}
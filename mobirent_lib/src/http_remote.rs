@@ -0,0 +1,112 @@
+//! Real HTTP-backed fleet access, talking to a remote fleet service over
+//! JSON.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::{Client, StatusCode};
+
+use crate::errors::FleetError;
+use crate::model::{Car, Fleet};
+use crate::traits::FleetAccess;
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+const MAX_RETRIES: u32 = 3;
+const INITIAL_BACKOFF: Duration = Duration::from_millis(200);
+
+/// `FleetAccess` implementation that talks to a real fleet service over
+/// HTTP, expecting `GET {base_url}/fleet` and `GET {base_url}/cars/{id}`
+/// endpoints returning JSON bodies matching [`Fleet`] and [`Car`].
+///
+/// Connection errors and 5xx responses are retried with exponential
+/// backoff before being mapped to [`FleetError::RemoteUnavailable`].
+pub struct HttpFleetAccess {
+    client: Client,
+    base_url: String,
+    bearer_token: Option<String>,
+}
+
+impl HttpFleetAccess {
+    /// Creates a client against `base_url` (no trailing slash), optionally
+    /// authenticating with a bearer token, using the default request
+    /// timeout.
+    pub fn new(base_url: impl Into<String>, bearer_token: Option<String>) -> Self {
+        Self::with_timeout(base_url, bearer_token, DEFAULT_TIMEOUT)
+    }
+
+    /// Like [`HttpFleetAccess::new`] but with an explicit request timeout.
+    pub fn with_timeout(
+        base_url: impl Into<String>,
+        bearer_token: Option<String>,
+        timeout: Duration,
+    ) -> Self {
+        let client = Client::builder()
+            .timeout(timeout)
+            .build()
+            .expect("reqwest client should always build with the default TLS config");
+        Self {
+            client,
+            base_url: base_url.into(),
+            bearer_token,
+        }
+    }
+
+    fn request(&self, path: &str) -> reqwest::RequestBuilder {
+        let mut req = self.client.get(format!("{}{path}", self.base_url));
+        if let Some(token) = &self.bearer_token {
+            req = req.bearer_auth(token);
+        }
+        req
+    }
+
+    /// Performs a GET against `path`, retrying with exponential backoff on
+    /// connection/timeout errors and 5xx responses.
+    async fn send_with_retry(&self, path: &str) -> Result<reqwest::Response, FleetError> {
+        let mut backoff = INITIAL_BACKOFF;
+        let mut last_err = None;
+
+        for attempt in 0..=MAX_RETRIES {
+            if attempt > 0 {
+                tokio::time::sleep(backoff).await;
+                backoff *= 2;
+            }
+
+            match self.request(path).send().await {
+                Ok(resp) if resp.status().is_server_error() => {
+                    last_err = Some(FleetError::RemoteUnavailable(format!(
+                        "{path} returned {}",
+                        resp.status()
+                    )));
+                }
+                Ok(resp) => return Ok(resp),
+                Err(err) if err.is_connect() || err.is_timeout() => {
+                    last_err = Some(FleetError::RemoteUnavailable(err.to_string()));
+                }
+                Err(err) => return Err(FleetError::RemoteUnavailable(err.to_string())),
+            }
+        }
+
+        Err(last_err.unwrap_or_else(|| FleetError::RemoteUnavailable(path.to_string())))
+    }
+}
+
+#[async_trait]
+impl FleetAccess for HttpFleetAccess {
+    async fn get_fleet(&self) -> Result<Fleet, FleetError> {
+        self.send_with_retry("/fleet")
+            .await?
+            .json::<Fleet>()
+            .await
+            .map_err(|err| FleetError::RemoteUnavailable(err.to_string()))
+    }
+
+    async fn get_car(&self, id: &str) -> Result<Car, FleetError> {
+        let resp = self.send_with_retry(&format!("/cars/{id}")).await?;
+        if resp.status() == StatusCode::NOT_FOUND {
+            return Err(FleetError::CarNotFound(id.to_string()));
+        }
+        resp.json::<Car>()
+            .await
+            .map_err(|err| FleetError::RemoteUnavailable(err.to_string()))
+    }
+}
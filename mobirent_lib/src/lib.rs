@@ -32,10 +32,15 @@
 //! ```
 
 // Internal modules — not exposed directly
+mod assets;
+mod balancer;
 mod model;
 mod proxy;
 mod remote;
+mod http_remote;
 mod cache;
+#[cfg(feature = "redis")]
+mod redis_cache;
 mod telemetry;
 mod errors;
 mod traits;
@@ -45,20 +50,48 @@ mod traits;
 /// Core proxy interface providing cached and remote access to fleet data.
 pub use proxy::FleetProxy;
 
+/// Token-bucket rate limiting for `FleetProxy::with_rate_limiter`.
+pub use proxy::RateLimiter;
+
 /// Data models representing car and fleet information.
 pub use model::{Car, Fleet};
 
 /// Fleet telemetry logging utilities.
 pub use telemetry::TelemetryLogger;
 
+/// A point-in-time snapshot of telemetry counters, returned by
+/// `TelemetryLogger::snapshot`.
+pub use telemetry::TelemetrySummary;
+
+/// Pluggable destination for flushed telemetry summaries, plus the
+/// built-in JSON-via-`log` sink.
+pub use telemetry::{LogSink, TelemetrySink};
+
 /// Cache management utilities for in-memory or file-backed caching.
 pub use cache::CacheManager;
 
+/// Redis-backed cache for sharing one fleet cache across proxy instances.
+/// Requires the `redis` feature.
+#[cfg(feature = "redis")]
+pub use redis_cache::RedisCacheBackend;
+
 /// Error types used across the Mobirent library.
 pub use errors::FleetError;
 
 /// Trait definitions for Fleet access abstractions.
-pub use traits::FleetAccess;
+pub use traits::{CacheBackend, FleetAccess};
 
-/// Remote implementation simulating cloud-based fleet data access.
+/// Remote implementation simulating cloud-based fleet data access, useful
+/// in tests.
 pub use remote::RemoteFleetAccess;
+
+/// Real HTTP-backed remote implementation, for talking to an actual fleet
+/// service.
+pub use http_remote::HttpFleetAccess;
+
+/// Backend-selection strategies for `FleetProxy::with_backends`.
+pub use balancer::LoadBalancer;
+
+/// Remote-or-local binary asset (car photos, documents) loading, used by
+/// `Car::fetch_image`.
+pub use assets::{copy_asset, load_asset, write_asset, Asset};
@@ -0,0 +1,56 @@
+//! Data models representing cars and fleets managed by Mobirent.
+
+use serde::{Deserialize, Serialize};
+
+use crate::assets::{self, Asset};
+use crate::cache::CacheManager;
+use crate::errors::FleetError;
+use crate::traits::CacheBackend;
+
+/// A single rentable vehicle tracked by the fleet service.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Car {
+    /// Unique identifier assigned by the fleet backend.
+    pub id: String,
+    pub make: String,
+    pub model: String,
+    pub year: u32,
+    /// Whether the car is currently available for rent.
+    pub available: bool,
+    /// Local path or `http(s)` URL to this car's photo, if any.
+    #[serde(default)]
+    pub image_url: Option<String>,
+}
+
+impl Car {
+    /// Fetches this car's photo, whether it lives at a local path or a
+    /// remote URL, caching the decoded bytes through `cache` so repeated
+    /// calls don't re-read or re-download the asset.
+    pub async fn fetch_image(&self, cache: &CacheManager) -> Result<Asset, FleetError> {
+        let Some(source) = &self.image_url else {
+            return Err(FleetError::AssetUnavailable(format!(
+                "car {} has no image configured",
+                self.id
+            )));
+        };
+
+        let cache_key = format!("asset:{source}");
+        if let Some(bytes) = cache.get(&cache_key).await {
+            if let Ok(asset) = rmp_serde::from_slice::<Asset>(&bytes) {
+                return Ok(asset);
+            }
+        }
+
+        let asset = assets::load_asset(source).await?;
+        if let Ok(bytes) = rmp_serde::to_vec(&asset) {
+            cache.set(&cache_key, bytes).await;
+        }
+        Ok(asset)
+    }
+}
+
+/// A collection of cars belonging to a single fleet.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Fleet {
+    pub cars: Vec<Car>,
+}
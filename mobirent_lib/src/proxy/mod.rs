@@ -0,0 +1,256 @@
+//! The [`FleetProxy`] type: the Proxy-pattern entry point that fronts one
+//! or more remote fleet backends with caching and telemetry.
+
+mod rate_limit;
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::balancer::{BackendHealth, LoadBalancer};
+use crate::cache::CacheManager;
+use crate::errors::FleetError;
+use crate::model::{Car, Fleet};
+use crate::telemetry::TelemetryLogger;
+use crate::traits::{CacheBackend, FleetAccess};
+
+pub use rate_limit::RateLimiter;
+
+const FLEET_KEY: &str = "__fleet__";
+
+struct Backend<R> {
+    remote: R,
+    health: BackendHealth,
+}
+
+/// Controlled access point for fleet data: serves cached data when
+/// possible, and otherwise routes to one of its remote backends.
+///
+/// A `FleetProxy` can front a single backend (via [`FleetProxy::new`]) or a
+/// pool of redundant backends behind a [`LoadBalancer`] strategy (via
+/// [`FleetProxy::with_backends`]). The cache is any [`CacheBackend`] — in
+/// memory, file-backed, or a shared networked store — and defaults to
+/// [`CacheManager`].
+pub struct FleetProxy<R: FleetAccess = crate::remote::RemoteFleetAccess, C: CacheBackend = CacheManager> {
+    backends: Vec<Backend<R>>,
+    balancer: LoadBalancer,
+    cache: C,
+    telemetry: TelemetryLogger,
+    rate_limiter: Option<RateLimiter>,
+}
+
+impl<R: FleetAccess, C: CacheBackend> FleetProxy<R, C> {
+    /// Creates a proxy fronting a single remote backend.
+    pub fn new(remote: R, cache: C) -> Self {
+        Self {
+            backends: vec![Backend {
+                remote,
+                health: BackendHealth::new(1),
+            }],
+            balancer: LoadBalancer::round_robin(),
+            cache,
+            telemetry: TelemetryLogger::new(),
+            rate_limiter: None,
+        }
+    }
+
+    /// Creates a proxy fronting a pool of weighted backends, routed
+    /// according to `strategy`.
+    ///
+    /// Each backend is paired with an integer weight; the weight only
+    /// matters to [`LoadBalancer::WeightedRoundRobin`] but is required
+    /// up front so switching strategies doesn't require re-registering
+    /// backends.
+    pub fn with_backends(backends: Vec<(R, u32)>, strategy: LoadBalancer, cache: C) -> Self {
+        Self {
+            backends: backends
+                .into_iter()
+                .map(|(remote, weight)| Backend {
+                    remote,
+                    health: BackendHealth::new(weight),
+                })
+                .collect(),
+            balancer: strategy,
+            cache,
+            telemetry: TelemetryLogger::new(),
+            rate_limiter: None,
+        }
+    }
+
+    /// Caps how many remote calls this proxy makes per second, shielding
+    /// upstream fleet services from bursts. Cache hits are unaffected.
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Replaces the default telemetry logger, e.g. with one configured
+    /// with a [`crate::telemetry::TelemetrySink`] via
+    /// [`TelemetryLogger::with_sink`].
+    pub fn with_telemetry(mut self, telemetry: TelemetryLogger) -> Self {
+        self.telemetry = telemetry;
+        self
+    }
+
+    /// Routes a single call to a backend, recording health and telemetry,
+    /// and routing with `key` for strategies that care about caller
+    /// affinity (currently [`LoadBalancer::IpHash`]).
+    async fn call_backend<'a, T>(
+        &'a self,
+        key: Option<&str>,
+        op: &str,
+        f: impl Fn(&'a R) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<T, FleetError>> + Send + 'a>>,
+    ) -> Result<T, FleetError> {
+        if let Some(limiter) = &self.rate_limiter {
+            limiter.acquire(&self.telemetry).await?;
+        }
+
+        let health: Vec<&BackendHealth> = self.backends.iter().map(|b| &b.health).collect();
+        let Some(idx) = self.balancer.select(&health, key) else {
+            return Err(FleetError::RemoteUnavailable(
+                "all backends are unhealthy".to_string(),
+            ));
+        };
+
+        let backend = &self.backends[idx];
+        backend.health.begin_call();
+        let started = std::time::Instant::now();
+        let result = f(&backend.remote).await;
+        let latency = started.elapsed();
+        backend.health.end_call();
+
+        match &result {
+            Ok(_) => {
+                backend.health.record_success();
+                self.telemetry.log_remote_call(op, idx, true, latency);
+            }
+            Err(err) => {
+                // Only transport/remote faults count toward unhealthiness.
+                // `CarNotFound` is a valid response from a perfectly healthy
+                // backend; counting it would trip the failure threshold (and
+                // the 30s cooldown) on ordinary 404 traffic.
+                if matches!(err, FleetError::RemoteUnavailable(_)) {
+                    backend.health.record_failure();
+                }
+                self.telemetry.log_remote_call(op, idx, false, latency);
+            }
+        }
+        result
+    }
+
+    /// Returns a serializable snapshot of everything this proxy's
+    /// telemetry has recorded: cache hit/miss counts, remote call
+    /// success/failure and latency, and per-backend request volume.
+    pub fn telemetry_snapshot(&self) -> crate::telemetry::TelemetrySummary {
+        self.telemetry.snapshot()
+    }
+
+    /// Reads `key` from the cache and decodes it, treating a missing entry
+    /// or a decode error identically: both are cache misses that fall
+    /// through to the remote backend.
+    async fn cache_get<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        let bytes = self.cache.get(key).await?;
+        match rmp_serde::from_slice(&bytes) {
+            Ok(value) => Some(value),
+            Err(err) => {
+                log::warn!("discarding undecodable cache entry {key}: {err}");
+                None
+            }
+        }
+    }
+
+    /// Encodes `value` and writes it to the cache at `key`. A failure to
+    /// encode only skips the cache write; it never fails the call.
+    async fn cache_set<T: Serialize>(&self, key: &str, value: &T) {
+        match rmp_serde::to_vec(value) {
+            Ok(bytes) => self.cache.set(key, bytes).await,
+            Err(err) => log::warn!("failed to encode cache entry {key}: {err}"),
+        }
+    }
+}
+
+#[async_trait]
+impl<R: FleetAccess, C: CacheBackend> FleetAccess for FleetProxy<R, C> {
+    async fn get_fleet(&self) -> Result<Fleet, FleetError> {
+        if let Some(fleet) = self.cache_get::<Fleet>(FLEET_KEY).await {
+            self.telemetry.log_cache_hit("get_fleet");
+            return Ok(fleet);
+        }
+        self.telemetry.log_cache_miss("get_fleet");
+
+        let fleet = self
+            .call_backend(None, "get_fleet", |remote| Box::pin(remote.get_fleet()))
+            .await?;
+        self.cache_set(FLEET_KEY, &fleet).await;
+        Ok(fleet)
+    }
+
+    async fn get_car(&self, id: &str) -> Result<Car, FleetError> {
+        if let Some(car) = self.cache_get::<Car>(id).await {
+            self.telemetry.log_cache_hit("get_car");
+            return Ok(car);
+        }
+        self.telemetry.log_cache_miss("get_car");
+
+        let car = self
+            .call_backend(Some(id), "get_car", |remote| Box::pin(remote.get_car(id)))
+            .await?;
+        self.cache_set(id, &car).await;
+        Ok(car)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FakeRemote {
+        err: fn() -> FleetError,
+    }
+
+    #[async_trait]
+    impl FleetAccess for FakeRemote {
+        async fn get_fleet(&self) -> Result<Fleet, FleetError> {
+            Err((self.err)())
+        }
+
+        async fn get_car(&self, _id: &str) -> Result<Car, FleetError> {
+            Err((self.err)())
+        }
+    }
+
+    #[tokio::test]
+    async fn car_not_found_does_not_mark_the_backend_unhealthy() {
+        let proxy = FleetProxy::new(
+            FakeRemote {
+                err: || FleetError::CarNotFound("missing".to_string()),
+            },
+            CacheManager::new(),
+        );
+
+        for _ in 0..5 {
+            assert!(matches!(
+                proxy.get_car("missing").await,
+                Err(FleetError::CarNotFound(_))
+            ));
+        }
+
+        assert!(proxy.backends[0].health.is_healthy());
+    }
+
+    #[tokio::test]
+    async fn remote_unavailable_marks_the_backend_unhealthy_after_the_threshold() {
+        let proxy = FleetProxy::new(
+            FakeRemote {
+                err: || FleetError::RemoteUnavailable("connection refused".to_string()),
+            },
+            CacheManager::new(),
+        );
+
+        for _ in 0..3 {
+            assert!(proxy.get_car("any").await.is_err());
+        }
+
+        assert!(!proxy.backends[0].health.is_healthy());
+    }
+}
@@ -0,0 +1,204 @@
+//! Token-bucket rate limiting for outgoing remote calls.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::errors::FleetError;
+use crate::telemetry::TelemetryLogger;
+
+struct BucketState {
+    tokens: f64,
+    rate: f64,
+    last_refill: Instant,
+}
+
+/// Refills `capacity` tokens at `rate` tokens/sec; each call consumes one.
+struct TokenBucket {
+    capacity: f64,
+    state: Mutex<BucketState>,
+}
+
+impl TokenBucket {
+    fn new(capacity: u32, rate: f64) -> Self {
+        let capacity = capacity as f64;
+        Self {
+            capacity,
+            state: Mutex::new(BucketState {
+                tokens: capacity,
+                rate,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    fn refill(state: &mut BucketState, capacity: f64) {
+        let elapsed = state.last_refill.elapsed();
+        state.tokens = (state.tokens + elapsed.as_secs_f64() * state.rate).min(capacity);
+        state.last_refill = Instant::now();
+    }
+
+    /// Takes one token if available, returning whether it did.
+    fn try_acquire(&self) -> bool {
+        let mut state = self.state.lock().unwrap();
+        Self::refill(&mut state, self.capacity);
+        if state.tokens >= 1.0 {
+            state.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// How long until at least one token will be available.
+    fn time_until_next_token(&self) -> Duration {
+        let state = self.state.lock().unwrap();
+        if state.tokens >= 1.0 || state.rate <= 0.0 {
+            return Duration::ZERO;
+        }
+        Duration::from_secs_f64((1.0 - state.tokens) / state.rate)
+    }
+
+    /// Overwrites the refill rate, used by dynamic rate limiting.
+    fn set_rate(&self, rate: f64) {
+        self.state.lock().unwrap().rate = rate;
+    }
+}
+
+/// Rate limits calls made through a [`FleetProxy`](crate::proxy::FleetProxy),
+/// protecting upstream fleet services from bursts.
+///
+/// In dynamic mode, the refill rate is halved whenever
+/// [`TelemetryLogger::remote_error_rate`] exceeds the configured
+/// threshold, and restored once it recovers.
+pub struct RateLimiter {
+    bucket: TokenBucket,
+    base_rate: f64,
+    dynamic_error_threshold: Option<f64>,
+    wait_when_limited: bool,
+}
+
+impl RateLimiter {
+    /// Caps throughput at `rate` tokens/sec with a burst capacity of
+    /// `capacity`. Requests beyond the limit are rejected with
+    /// [`FleetError::RateLimited`].
+    pub fn new(capacity: u32, rate: f64) -> Self {
+        Self {
+            bucket: TokenBucket::new(capacity, rate),
+            base_rate: rate,
+            dynamic_error_threshold: None,
+            wait_when_limited: false,
+        }
+    }
+
+    /// Like [`RateLimiter::new`], but the limit auto-tightens (halves)
+    /// once the proxy's recent remote error rate exceeds
+    /// `error_rate_threshold` (a fraction in `[0, 1]`), and relaxes again
+    /// once the error rate recovers.
+    pub fn dynamic(capacity: u32, rate: f64, error_rate_threshold: f64) -> Self {
+        Self {
+            dynamic_error_threshold: Some(error_rate_threshold),
+            ..Self::new(capacity, rate)
+        }
+    }
+
+    /// Instead of rejecting calls once the bucket is empty, await the next
+    /// refill.
+    pub fn wait_when_limited(mut self) -> Self {
+        self.wait_when_limited = true;
+        self
+    }
+
+    /// Consumes one token, waiting or erroring per configuration if the
+    /// bucket is empty.
+    pub(crate) async fn acquire(&self, telemetry: &TelemetryLogger) -> Result<(), FleetError> {
+        if let Some(threshold) = self.dynamic_error_threshold {
+            let target_rate = if telemetry.remote_error_rate() > threshold {
+                self.base_rate / 2.0
+            } else {
+                self.base_rate
+            };
+            self.bucket.set_rate(target_rate);
+        }
+
+        if self.bucket.try_acquire() {
+            return Ok(());
+        }
+        if !self.wait_when_limited {
+            return Err(FleetError::RateLimited);
+        }
+        // Keep sleeping and re-checking rather than assuming one sleep is
+        // enough: float rounding or a concurrent acquire can mean the
+        // token still isn't there when we wake up.
+        loop {
+            tokio::time::sleep(self.bucket.time_until_next_token()).await;
+            if self.bucket.try_acquire() {
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drains_up_to_capacity_then_rejects() {
+        let bucket = TokenBucket::new(3, 1.0);
+        assert!(bucket.try_acquire());
+        assert!(bucket.try_acquire());
+        assert!(bucket.try_acquire());
+        assert!(!bucket.try_acquire());
+    }
+
+    #[test]
+    fn refills_over_time_up_to_capacity() {
+        let bucket = TokenBucket::new(2, 1000.0);
+        assert!(bucket.try_acquire());
+        assert!(bucket.try_acquire());
+        assert!(!bucket.try_acquire());
+
+        std::thread::sleep(Duration::from_millis(10));
+        // At 1000 tokens/sec, 10ms is enough to refill both tokens, but
+        // refill caps at capacity rather than accumulating unboundedly.
+        assert!(bucket.try_acquire());
+        assert!(bucket.try_acquire());
+        assert!(!bucket.try_acquire());
+    }
+
+    #[test]
+    fn time_until_next_token_is_zero_when_tokens_available() {
+        let bucket = TokenBucket::new(1, 1.0);
+        assert_eq!(bucket.time_until_next_token(), Duration::ZERO);
+    }
+
+    #[test]
+    fn time_until_next_token_reflects_the_configured_rate() {
+        let bucket = TokenBucket::new(1, 2.0);
+        assert!(bucket.try_acquire());
+        let wait = bucket.time_until_next_token();
+        assert!(wait > Duration::ZERO && wait <= Duration::from_millis(500));
+    }
+
+    #[tokio::test]
+    async fn acquire_rejects_once_empty_by_default() {
+        let bucket = RateLimiter::new(1, 1.0);
+        let telemetry = TelemetryLogger::new();
+        assert!(bucket.acquire(&telemetry).await.is_ok());
+        assert!(matches!(
+            bucket.acquire(&telemetry).await,
+            Err(FleetError::RateLimited)
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn wait_when_limited_blocks_until_a_token_is_available() {
+        let limiter = RateLimiter::new(1, 10.0).wait_when_limited();
+        let telemetry = TelemetryLogger::new();
+        assert!(limiter.acquire(&telemetry).await.is_ok());
+
+        // The bucket is now empty; `acquire` must not return until a
+        // token has actually been refilled and consumed.
+        limiter.acquire(&telemetry).await.unwrap();
+    }
+}
@@ -0,0 +1,66 @@
+//! Redis-backed [`CacheBackend`], for deployments where several MobiRent
+//! proxy instances should share one fleet cache.
+//!
+//! Requires the `redis` feature.
+
+use async_trait::async_trait;
+use redis::aio::ConnectionManager;
+use redis::AsyncCommands;
+
+use crate::traits::CacheBackend;
+
+/// Cache backend storing entries in Redis. All keys are namespaced so
+/// multiple independent MobiRent deployments can share one Redis instance
+/// without colliding.
+///
+/// Uses a [`ConnectionManager`], which multiplexes and automatically
+/// reconnects a single connection, rather than a hand-rolled pool.
+pub struct RedisCacheBackend {
+    conn: ConnectionManager,
+    namespace: String,
+}
+
+impl RedisCacheBackend {
+    /// Connects to `redis_url` (e.g. `redis://127.0.0.1/`), namespacing
+    /// all keys under `namespace`.
+    pub async fn connect(redis_url: &str, namespace: impl Into<String>) -> redis::RedisResult<Self> {
+        let client = redis::Client::open(redis_url)?;
+        let conn = ConnectionManager::new(client).await?;
+        Ok(Self {
+            conn,
+            namespace: namespace.into(),
+        })
+    }
+
+    fn namespaced(&self, key: &str) -> String {
+        format!("{}:{key}", self.namespace)
+    }
+}
+
+#[async_trait]
+impl CacheBackend for RedisCacheBackend {
+    async fn get(&self, key: &str) -> Option<Vec<u8>> {
+        let mut conn = self.conn.clone();
+        match conn.get::<_, Option<Vec<u8>>>(self.namespaced(key)).await {
+            Ok(value) => value,
+            Err(err) => {
+                log::warn!("redis get failed, degrading to remote fetch: {err}");
+                None
+            }
+        }
+    }
+
+    async fn set(&self, key: &str, value: Vec<u8>) {
+        let mut conn = self.conn.clone();
+        if let Err(err) = conn.set::<_, _, ()>(self.namespaced(key), value).await {
+            log::warn!("redis set failed: {err}");
+        }
+    }
+
+    async fn invalidate(&self, key: &str) {
+        let mut conn = self.conn.clone();
+        if let Err(err) = conn.del::<_, ()>(self.namespaced(key)).await {
+            log::warn!("redis invalidate failed: {err}");
+        }
+    }
+}
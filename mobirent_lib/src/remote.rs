@@ -0,0 +1,51 @@
+//! Simulated cloud-based fleet data access.
+
+use async_trait::async_trait;
+
+use crate::errors::FleetError;
+use crate::model::{Car, Fleet};
+use crate::traits::FleetAccess;
+
+/// Stand-in for a remote fleet service, for tests and demos that should not
+/// need a live server (see [`crate::HttpFleetAccess`] for the real client).
+/// Returns a small fixed set of cars so callers can exercise caching and
+/// proxying logic without a network dependency.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RemoteFleetAccess;
+
+#[async_trait]
+impl FleetAccess for RemoteFleetAccess {
+    async fn get_fleet(&self) -> Result<Fleet, FleetError> {
+        Ok(Fleet {
+            cars: sample_cars(),
+        })
+    }
+
+    async fn get_car(&self, id: &str) -> Result<Car, FleetError> {
+        sample_cars()
+            .into_iter()
+            .find(|car| car.id == id)
+            .ok_or_else(|| FleetError::CarNotFound(id.to_string()))
+    }
+}
+
+fn sample_cars() -> Vec<Car> {
+    vec![
+        Car {
+            id: "car-1".to_string(),
+            make: "Toyota".to_string(),
+            model: "Corolla".to_string(),
+            year: 2021,
+            available: true,
+            image_url: None,
+        },
+        Car {
+            id: "car-2".to_string(),
+            make: "Honda".to_string(),
+            model: "Civic".to_string(),
+            year: 2020,
+            available: false,
+            image_url: None,
+        },
+    ]
+}
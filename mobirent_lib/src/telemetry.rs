@@ -0,0 +1,254 @@
+//! Structured fleet telemetry: counters and latency stats for cache
+//! outcomes, remote calls, and per-backend request volume, with a
+//! pluggable export hook.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// Weight given to each new remote call outcome when updating
+/// [`TelemetryLogger::remote_error_rate`]'s exponential moving average.
+/// Higher values track recent behavior more closely; lower values smooth
+/// over brief blips. `0.2` weights roughly the last 5-10 calls.
+const ERROR_RATE_DECAY: f64 = 0.2;
+
+/// A flushable destination for telemetry snapshots, e.g. writing JSON to
+/// disk or pushing to a metrics endpoint.
+pub trait TelemetrySink: Send + Sync {
+    fn publish(&self, summary: &TelemetrySummary);
+}
+
+/// Sink that logs the summary as JSON via the `log` crate. It is only used
+/// when passed to [`TelemetryLogger::with_sink`]; a logger without a sink
+/// publishes nothing on flush.
+#[derive(Debug, Default)]
+pub struct LogSink;
+
+impl TelemetrySink for LogSink {
+    fn publish(&self, summary: &TelemetrySummary) {
+        match serde_json::to_string(summary) {
+            Ok(json) => log::info!("telemetry: {json}"),
+            Err(err) => log::warn!("failed to serialize telemetry summary: {err}"),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct LatencyStats {
+    count: AtomicU64,
+    total_micros: AtomicU64,
+    max_micros: AtomicU64,
+}
+
+impl LatencyStats {
+    fn record(&self, latency: Duration) {
+        let micros = latency.as_micros() as u64;
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.total_micros.fetch_add(micros, Ordering::Relaxed);
+        self.max_micros.fetch_max(micros, Ordering::Relaxed);
+    }
+
+    fn snapshot(&self) -> LatencySummary {
+        let count = self.count.load(Ordering::Relaxed);
+        let total = self.total_micros.load(Ordering::Relaxed);
+        LatencySummary {
+            count,
+            mean_micros: if count == 0 { 0.0 } else { total as f64 / count as f64 },
+            max_micros: self.max_micros.load(Ordering::Relaxed),
+        }
+    }
+
+    fn reset(&self) {
+        self.count.store(0, Ordering::Relaxed);
+        self.total_micros.store(0, Ordering::Relaxed);
+        self.max_micros.store(0, Ordering::Relaxed);
+    }
+}
+
+/// Mean/max latency for a set of recorded remote calls.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct LatencySummary {
+    pub count: u64,
+    pub mean_micros: f64,
+    pub max_micros: u64,
+}
+
+/// A point-in-time snapshot of everything [`TelemetryLogger`] has
+/// recorded since the last [`TelemetryLogger::reset`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TelemetrySummary {
+    pub cache_hits: u64,
+    pub cache_misses: u64,
+    pub remote_successes: u64,
+    pub remote_failures: u64,
+    pub remote_latency: LatencySummary,
+    pub requests_per_backend: HashMap<usize, u64>,
+}
+
+/// Records cache and remote-access events for observability, and exposes
+/// them as a [`TelemetrySummary`] that can be flushed through a pluggable
+/// [`TelemetrySink`].
+#[derive(Default)]
+pub struct TelemetryLogger {
+    cache_hits: AtomicU64,
+    cache_misses: AtomicU64,
+    remote_successes: AtomicU64,
+    remote_failures: AtomicU64,
+    remote_latency: LatencyStats,
+    requests_per_backend: Mutex<HashMap<usize, u64>>,
+    /// Exponential moving average of remote call failures, decayed by
+    /// [`ERROR_RATE_DECAY`] on every call so it reflects recent behavior
+    /// rather than the lifetime success/failure counts.
+    recent_error_rate: Mutex<f64>,
+    sink: Option<Box<dyn TelemetrySink>>,
+}
+
+impl fmt::Debug for TelemetryLogger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TelemetryLogger")
+            .field("summary", &self.snapshot())
+            .finish()
+    }
+}
+
+impl TelemetryLogger {
+    /// Creates a logger with no sink; `flush` is then a no-op.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a logger that publishes to `sink` on each [`Self::flush`].
+    pub fn with_sink(sink: impl TelemetrySink + 'static) -> Self {
+        Self {
+            sink: Some(Box::new(sink)),
+            ..Self::default()
+        }
+    }
+
+    /// Records that an operation was served from cache.
+    pub fn log_cache_hit(&self, operation: &str) {
+        self.cache_hits.fetch_add(1, Ordering::Relaxed);
+        log::debug!("cache hit: {operation}");
+    }
+
+    /// Records that an operation missed the cache and fell through to the
+    /// remote backend.
+    pub fn log_cache_miss(&self, operation: &str) {
+        self.cache_misses.fetch_add(1, Ordering::Relaxed);
+        log::debug!("cache miss: {operation}");
+    }
+
+    /// Records the outcome and latency of a call to backend `backend_index`.
+    pub fn log_remote_call(&self, operation: &str, backend_index: usize, success: bool, latency: Duration) {
+        if success {
+            self.remote_successes.fetch_add(1, Ordering::Relaxed);
+            log::info!("remote call succeeded: {operation} ({latency:?})");
+        } else {
+            self.remote_failures.fetch_add(1, Ordering::Relaxed);
+            log::warn!("remote call failed: {operation} ({latency:?})");
+        }
+        self.remote_latency.record(latency);
+        *self
+            .requests_per_backend
+            .lock()
+            .unwrap()
+            .entry(backend_index)
+            .or_insert(0) += 1;
+
+        let sample = if success { 0.0 } else { 1.0 };
+        let mut rate = self.recent_error_rate.lock().unwrap();
+        *rate = ERROR_RATE_DECAY * sample + (1.0 - ERROR_RATE_DECAY) * *rate;
+    }
+
+    /// Recency-weighted fraction of remote calls that have failed, in
+    /// `[0, 1]`, used to drive [`crate::proxy::RateLimiter`]'s dynamic
+    /// mode. Unlike a lifetime success/failure ratio, this decays: a
+    /// backend that recovers after a burst of failures sees this value
+    /// fall back down rather than staying elevated forever.
+    pub fn remote_error_rate(&self) -> f64 {
+        *self.recent_error_rate.lock().unwrap()
+    }
+
+    /// Returns a serializable snapshot of all counters recorded so far.
+    pub fn snapshot(&self) -> TelemetrySummary {
+        TelemetrySummary {
+            cache_hits: self.cache_hits.load(Ordering::Relaxed),
+            cache_misses: self.cache_misses.load(Ordering::Relaxed),
+            remote_successes: self.remote_successes.load(Ordering::Relaxed),
+            remote_failures: self.remote_failures.load(Ordering::Relaxed),
+            remote_latency: self.remote_latency.snapshot(),
+            requests_per_backend: self.requests_per_backend.lock().unwrap().clone(),
+        }
+    }
+
+    /// Zeroes every counter, e.g. after a periodic flush.
+    pub fn reset(&self) {
+        self.cache_hits.store(0, Ordering::Relaxed);
+        self.cache_misses.store(0, Ordering::Relaxed);
+        self.remote_successes.store(0, Ordering::Relaxed);
+        self.remote_failures.store(0, Ordering::Relaxed);
+        self.remote_latency.reset();
+        self.requests_per_backend.lock().unwrap().clear();
+        *self.recent_error_rate.lock().unwrap() = 0.0;
+    }
+
+    /// Publishes the current snapshot through the configured sink, if
+    /// any. Does not reset counters; call [`Self::reset`] separately if
+    /// each flush should start a fresh window.
+    pub fn flush(&self) {
+        if let Some(sink) = &self.sink {
+            sink.publish(&self.snapshot());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log(logger: &TelemetryLogger, outcomes: &[bool]) {
+        for &success in outcomes {
+            logger.log_remote_call("op", 0, success, Duration::ZERO);
+        }
+    }
+
+    #[test]
+    fn no_calls_means_zero_error_rate() {
+        let logger = TelemetryLogger::new();
+        assert_eq!(logger.remote_error_rate(), 0.0);
+    }
+
+    #[test]
+    fn a_burst_of_failures_raises_the_error_rate() {
+        let logger = TelemetryLogger::new();
+        log(&logger, &[false; 10]);
+        assert!(logger.remote_error_rate() > 0.8);
+    }
+
+    #[test]
+    fn error_rate_decays_back_down_after_recovery() {
+        let logger = TelemetryLogger::new();
+        log(&logger, &[false; 10]);
+        let after_burst = logger.remote_error_rate();
+
+        log(&logger, &[true; 20]);
+        let after_recovery = logger.remote_error_rate();
+
+        // A lifetime ratio would stay stuck at 1/3 forever; the decayed
+        // rate should fall back close to zero once failures stop.
+        assert!(after_recovery < after_burst);
+        assert!(after_recovery < 0.1);
+    }
+
+    #[test]
+    fn reset_zeroes_the_error_rate() {
+        let logger = TelemetryLogger::new();
+        log(&logger, &[false; 10]);
+        logger.reset();
+        assert_eq!(logger.remote_error_rate(), 0.0);
+    }
+}
@@ -0,0 +1,38 @@
+//! Trait definitions for fleet access abstractions.
+
+use async_trait::async_trait;
+
+use crate::errors::FleetError;
+use crate::model::{Car, Fleet};
+
+/// Common interface implemented by anything that can serve fleet and car
+/// data, whether that's a remote backend, a cache, or a proxy composing
+/// both.
+#[async_trait]
+pub trait FleetAccess: Send + Sync {
+    /// Fetches the full fleet.
+    async fn get_fleet(&self) -> Result<Fleet, FleetError>;
+
+    /// Fetches a single car by id.
+    async fn get_car(&self, id: &str) -> Result<Car, FleetError>;
+}
+
+/// A key/value store `FleetProxy` can use to cache serialized fleet and
+/// car data, independent of where that store actually lives (in memory,
+/// on disk, or behind a network call to a shared cache).
+///
+/// Implementations are expected to degrade gracefully: a backend that
+/// fails should return `None` from `get` rather than propagating an
+/// error, so the proxy simply falls through to the remote backend.
+#[async_trait]
+pub trait CacheBackend: Send + Sync {
+    /// Returns the raw bytes stored at `key`, or `None` if absent, stale,
+    /// or unreachable.
+    async fn get(&self, key: &str) -> Option<Vec<u8>>;
+
+    /// Stores `value` at `key`.
+    async fn set(&self, key: &str, value: Vec<u8>);
+
+    /// Removes the entry at `key`, if any.
+    async fn invalidate(&self, key: &str);
+}